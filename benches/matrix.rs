@@ -1,6 +1,6 @@
 use std::hint::black_box;
 
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use rsoderh_simd::{matrix_simd::Matrix4x4Simd, matrix_sisd::Matrix4x4Sisd};
 
 // fn multiply(matrix)
@@ -19,8 +19,8 @@ fn criterion_benchmark(c: &mut Criterion) {
         [3., 0., 0., 1.],
     ]);
 
-    let matrix_a = matrix_a_src.clone();
-    let matrix_b = matrix_b_src.clone();
+    let matrix_a = matrix_a_src;
+    let matrix_b = matrix_b_src;
 
     c.bench_function("matrix_sisd", |b| {
         b.iter(|| black_box(&matrix_a) * black_box(&matrix_b))
@@ -34,5 +34,43 @@ fn criterion_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, criterion_benchmark);
+/// Sweeps batch sizes to compare [`Matrix4x4Simd::multiply_batch`] against calling the `&M * &M`
+/// operator in a scalar loop, showing how the batched entry point amortizes feature detection
+/// and stays memory-bound at larger sizes.
+fn batch_benchmark(c: &mut Criterion) {
+    let matrix_a = Matrix4x4Simd::from_rows([
+        [1., 2., 0., 1.],
+        [0., 1., 3., 2.],
+        [4., 0., 1., 0.],
+        [2., 1., 0., 1.],
+    ]);
+    let matrix_b = Matrix4x4Simd::from_rows([
+        [2., 1., 3., 0.],
+        [1., 0., 2., 1.],
+        [0., 1., 1., 2.],
+        [3., 0., 0., 1.],
+    ]);
+
+    let mut group = c.benchmark_group("matrix_simd_batch_multiply");
+    for size in [16, 256, 4096] {
+        let lhs = vec![matrix_a; size];
+        let rhs = vec![matrix_b; size];
+        let mut out = vec![Matrix4x4Simd::ZERO; size];
+
+        group.bench_with_input(BenchmarkId::new("multiply_batch", size), &size, |b, _| {
+            b.iter(|| Matrix4x4Simd::multiply_batch(black_box(&lhs), black_box(&rhs), &mut out))
+        });
+
+        group.bench_with_input(BenchmarkId::new("scalar_loop", size), &size, |b, _| {
+            b.iter(|| {
+                for (o, (l, r)) in out.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+                    *o = black_box(l) * black_box(r);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark, batch_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,4 @@
+pub mod matrix;
+pub mod matrix_simd;
+pub mod matrix_sisd;
+pub mod vec4;
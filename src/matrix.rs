@@ -0,0 +1,384 @@
+use std::{iter::Sum, ops};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// A row-major `M`×`N` matrix backed by `[[T; N]; M]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<T, const M: usize, const N: usize>(pub [[T; N]; M]);
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn new(rows: [[T; N]; M]) -> Self {
+        Self(rows)
+    }
+
+    pub const fn nrows(&self) -> usize {
+        M
+    }
+
+    pub const fn ncols(&self) -> usize {
+        N
+    }
+
+    /// Iterates over every cell in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().flat_map(|row| row.iter())
+    }
+
+    /// Iterates over the rows of the matrix.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T; N]> {
+        self.0.iter()
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
+        Self(
+            rows.into_iter()
+                .map(|iter| {
+                    iter.into_iter()
+                        .collect::<Box<[_]>>()
+                        .as_ref()
+                        .try_into()
+                        .unwrap()
+                })
+                .collect::<Box<[_]>>()
+                .as_ref()
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    pub fn map(self, f: impl Fn(T) -> T) -> Self {
+        Self(self.0.map(|row| row.map(&f)))
+    }
+}
+
+impl<T: Default, const M: usize, const N: usize> Default for Matrix<T, M, N> {
+    fn default() -> Self {
+        Self(std::array::from_fn(|_| std::array::from_fn(|_| T::default())))
+    }
+}
+
+impl<T, const M: usize, const N: usize> ops::Index<(usize, usize)> for Matrix<T, M, N> {
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.0[index.1][index.0]
+    }
+}
+
+impl<T, const M: usize, const N: usize> ops::IndexMut<(usize, usize)> for Matrix<T, M, N> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.0[index.1][index.0]
+    }
+}
+
+/// Indexing by a single row number returns the whole row.
+impl<T, const M: usize, const N: usize> ops::Index<usize> for Matrix<T, M, N> {
+    type Output = [T; N];
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, const M: usize, const N: usize> ops::IndexMut<usize> for Matrix<T, M, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T, const M: usize, const K: usize, const N: usize> ops::Mul<&Matrix<T, K, N>>
+    for &Matrix<T, M, K>
+where
+    T: Copy + Default + ops::Mul<Output = T> + Sum,
+{
+    type Output = Matrix<T, M, N>;
+    fn mul(self, rhs: &Matrix<T, K, N>) -> Self::Output {
+        let mut result = Matrix::<T, M, N>::default();
+        for row in 0..M {
+            for column in 0..N {
+                result[(column, row)] = (0..K).map(|k| self[(k, row)] * rhs[(column, k)]).sum();
+            }
+        }
+
+        result
+    }
+}
+
+/// Implements `$trait` for every owned/reference permutation of a binary, elementwise matrix
+/// operator, in terms of `self.0[row][col] $op rhs.0[row][col]`.
+macro_rules! impl_elementwise_binop {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, const M: usize, const N: usize> ops::$trait<Matrix<T, M, N>> for Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait<Output = T>,
+        {
+            type Output = Matrix<T, M, N>;
+            fn $method(self, rhs: Matrix<T, M, N>) -> Self::Output {
+                Matrix(std::array::from_fn(|row| {
+                    std::array::from_fn(|col| self.0[row][col] $op rhs.0[row][col])
+                }))
+            }
+        }
+
+        impl<T, const M: usize, const N: usize> ops::$trait<&Matrix<T, M, N>> for Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait<Output = T>,
+        {
+            type Output = Matrix<T, M, N>;
+            fn $method(self, rhs: &Matrix<T, M, N>) -> Self::Output {
+                ops::$trait::$method(self, *rhs)
+            }
+        }
+
+        impl<T, const M: usize, const N: usize> ops::$trait<Matrix<T, M, N>> for &Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait<Output = T>,
+        {
+            type Output = Matrix<T, M, N>;
+            fn $method(self, rhs: Matrix<T, M, N>) -> Self::Output {
+                ops::$trait::$method(*self, rhs)
+            }
+        }
+
+        impl<T, const M: usize, const N: usize> ops::$trait<&Matrix<T, M, N>> for &Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait<Output = T>,
+        {
+            type Output = Matrix<T, M, N>;
+            fn $method(self, rhs: &Matrix<T, M, N>) -> Self::Output {
+                ops::$trait::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+impl_elementwise_binop!(Add, add, +);
+impl_elementwise_binop!(Sub, sub, -);
+
+/// Implements `$trait` (`AddAssign`/`SubAssign`) for both the owned and by-reference right-hand
+/// side of an elementwise matrix operator.
+macro_rules! impl_elementwise_binop_assign {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, const M: usize, const N: usize> ops::$trait<Matrix<T, M, N>> for Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait,
+        {
+            fn $method(&mut self, rhs: Matrix<T, M, N>) {
+                for row in 0..M {
+                    for col in 0..N {
+                        self.0[row][col] $op rhs.0[row][col];
+                    }
+                }
+            }
+        }
+
+        impl<T, const M: usize, const N: usize> ops::$trait<&Matrix<T, M, N>> for Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait,
+        {
+            fn $method(&mut self, rhs: &Matrix<T, M, N>) {
+                ops::$trait::$method(self, *rhs)
+            }
+        }
+    };
+}
+
+impl_elementwise_binop_assign!(AddAssign, add_assign, +=);
+impl_elementwise_binop_assign!(SubAssign, sub_assign, -=);
+
+/// Implements scalar `$trait` (`Mul<T>`/`Div<T>`) for both owned and by-reference `Matrix<T, M,
+/// N>`, applying the scalar to every cell.
+macro_rules! impl_scalar_binop {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, const M: usize, const N: usize> ops::$trait<T> for Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait<Output = T>,
+        {
+            type Output = Matrix<T, M, N>;
+            fn $method(self, rhs: T) -> Self::Output {
+                Matrix(std::array::from_fn(|row| {
+                    std::array::from_fn(|col| self.0[row][col] $op rhs)
+                }))
+            }
+        }
+
+        impl<T, const M: usize, const N: usize> ops::$trait<T> for &Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait<Output = T>,
+        {
+            type Output = Matrix<T, M, N>;
+            fn $method(self, rhs: T) -> Self::Output {
+                ops::$trait::$method(*self, rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_binop!(Mul, mul, *);
+impl_scalar_binop!(Div, div, /);
+
+/// Implements scalar `$trait` (`MulAssign<T>`/`DivAssign<T>`) in place, over every cell.
+macro_rules! impl_scalar_binop_assign {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, const M: usize, const N: usize> ops::$trait<T> for Matrix<T, M, N>
+        where
+            T: Copy + ops::$trait,
+        {
+            fn $method(&mut self, rhs: T) {
+                for row in self.0.iter_mut() {
+                    for cell in row.iter_mut() {
+                        *cell $op rhs;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_scalar_binop_assign!(MulAssign, mul_assign, *=);
+impl_scalar_binop_assign!(DivAssign, div_assign, /=);
+
+impl<T, const M: usize, const N: usize> ops::Neg for Matrix<T, M, N>
+where
+    T: Copy + ops::Neg<Output = T>,
+{
+    type Output = Matrix<T, M, N>;
+    fn neg(self) -> Self::Output {
+        Matrix(std::array::from_fn(|row| {
+            std::array::from_fn(|col| -self.0[row][col])
+        }))
+    }
+}
+
+impl<T, const M: usize, const N: usize> ops::Neg for &Matrix<T, M, N>
+where
+    T: Copy + ops::Neg<Output = T>,
+{
+    type Output = Matrix<T, M, N>;
+    fn neg(self) -> Self::Output {
+        ops::Neg::neg(*self)
+    }
+}
+
+impl<T, const M: usize, const N: usize> AbsDiffEq for Matrix<T, M, N>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon.clone()))
+    }
+}
+
+impl<T, const M: usize, const N: usize> RelativeEq for Matrix<T, M, N>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<T, const M: usize, const N: usize> UlpsEq for Matrix<T, M, N>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(lhs, rhs)| lhs.ulps_eq(rhs, epsilon.clone(), max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_multiply_non_square() {
+        // A 2x3 matrix times a 3x4 matrix yields a 2x4 matrix.
+        let a = Matrix::<f32, 2, 3>::from_rows([[1., 2., 3.], [4., 5., 6.]]);
+        let b = Matrix::<f32, 3, 4>::from_rows([
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+        ]);
+        let expected =
+            Matrix::<f32, 2, 4>::from_rows([[38., 44., 50., 56.], [83., 98., 113., 128.]]);
+
+        assert_abs_diff_eq!(expected, &a * &b);
+    }
+
+    #[test]
+    fn test_iter_rows() {
+        let matrix = Matrix::<f32, 2, 2>::from_rows([[1., 2.], [3., 4.]]);
+        let rows: Vec<_> = matrix.iter_rows().collect();
+        assert_eq!(rows, vec![&[1., 2.], &[3., 4.]]);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)] // Deliberately exercises every ref/value permutation.
+    fn test_add_and_sub_permutations() {
+        let a = Matrix::<f32, 2, 2>::from_rows([[1., 2.], [3., 4.]]);
+        let b = Matrix::<f32, 2, 2>::from_rows([[5., 6.], [7., 8.]]);
+        let expected_sum = Matrix::<f32, 2, 2>::from_rows([[6., 8.], [10., 12.]]);
+        let expected_diff = Matrix::<f32, 2, 2>::from_rows([[-4., -4.], [-4., -4.]]);
+
+        assert_abs_diff_eq!(expected_sum, a + b);
+        assert_abs_diff_eq!(expected_sum, a + &b);
+        assert_abs_diff_eq!(expected_sum, &a + b);
+        assert_abs_diff_eq!(expected_sum, &a + &b);
+        assert_abs_diff_eq!(expected_diff, a - b);
+
+        let mut sum = a;
+        sum += b;
+        assert_abs_diff_eq!(expected_sum, sum);
+        let mut sum = a;
+        sum += &b;
+        assert_abs_diff_eq!(expected_sum, sum);
+    }
+
+    #[test]
+    fn test_scalar_mul_div_and_neg() {
+        let a = Matrix::<f32, 2, 2>::from_rows([[1., 2.], [3., 4.]]);
+        let expected_scaled = Matrix::<f32, 2, 2>::from_rows([[2., 4.], [6., 8.]]);
+
+        assert_abs_diff_eq!(expected_scaled, a * 2.);
+        assert_abs_diff_eq!(expected_scaled, &a * 2.);
+        assert_abs_diff_eq!(a, expected_scaled / 2.);
+        assert_abs_diff_eq!(-a, Matrix::<f32, 2, 2>::from_rows([[-1., -2.], [-3., -4.]]));
+
+        let mut scaled = a;
+        scaled *= 2.;
+        assert_abs_diff_eq!(expected_scaled, scaled);
+        scaled /= 2.;
+        assert_abs_diff_eq!(a, scaled);
+    }
+}
@@ -1,46 +1,77 @@
-use std::{arch::x86_64, fmt::Debug, ops};
+use std::{
+    arch::x86_64,
+    fmt::Debug,
+    ops,
+    sync::atomic::{AtomicU8, Ordering},
+};
 
-use approx::AbsDiffEq;
+use crate::{matrix_sisd::Matrix4x4Sisd, vec4::Vec4};
 
-use crate::matrix_sisd::Matrix4x4Sisd;
+const AVX_FMA_SUPPORT_UNKNOWN: u8 = 0;
+const AVX_FMA_SUPPORT_YES: u8 = 1;
+const AVX_FMA_SUPPORT_NO: u8 = 2;
 
-#[derive(Copy, Clone, PartialEq, bytemuck::AnyBitPattern)]
+static AVX_FMA_SUPPORT: AtomicU8 = AtomicU8::new(AVX_FMA_SUPPORT_UNKNOWN);
+
+/// Detects AVX+FMA support once and caches the result for subsequent calls.
+fn avx_fma_supported() -> bool {
+    match AVX_FMA_SUPPORT.load(Ordering::Relaxed) {
+        AVX_FMA_SUPPORT_YES => true,
+        AVX_FMA_SUPPORT_NO => false,
+        _ => {
+            let supported = std::arch::is_x86_feature_detected!("avx")
+                && std::arch::is_x86_feature_detected!("fma");
+            AVX_FMA_SUPPORT.store(
+                if supported {
+                    AVX_FMA_SUPPORT_YES
+                } else {
+                    AVX_FMA_SUPPORT_NO
+                },
+                Ordering::Relaxed,
+            );
+            supported
+        }
+    }
+}
+
+/// A 4x4 `f32` matrix specialized for AVX, wrapping [`Matrix4x4Sisd`] to reuse its
+/// construction, indexing, and `approx` machinery while adding SIMD-specific layout and
+/// operations.
+#[derive(Copy, Clone, PartialEq)]
 #[repr(C, align(32))]
-pub struct Matrix4x4Simd([[f32; 4]; 4]);
+pub struct Matrix4x4Simd(Matrix4x4Sisd);
+
+// SAFETY: `Matrix4x4Sisd` is a plain `[[f32; 4]; 4]`, so every bit pattern is a valid
+// `Matrix4x4Simd`; the `align(32)` repr only widens alignment and introduces no padding since
+// the wrapped data is already 64 bytes (a multiple of 32).
+unsafe impl bytemuck::Zeroable for Matrix4x4Simd {}
+// SAFETY: see above.
+unsafe impl bytemuck::AnyBitPattern for Matrix4x4Simd {}
 
 impl Matrix4x4Simd {
-    pub const ZERO: Self = Self([[0.; 4]; 4]);
+    pub const ZERO: Self = Self(Matrix4x4Sisd::ZERO);
 
-    pub const IDENTITY: Self = Self([
-        [1., 0., 0., 0.],
-        [0., 1., 0., 0.],
-        [0., 0., 1., 0.],
-        [0., 0., 0., 1.],
-    ]);
+    pub const IDENTITY: Self = Self(Matrix4x4Sisd::IDENTITY);
 
     pub fn new(rows: [[f32; 4]; 4]) -> Self {
-        Self(rows)
+        Self(Matrix4x4Sisd::new(rows))
     }
 
     pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = f32>>) -> Self {
-        Self(
-            rows.into_iter()
-                .map(|iter| {
-                    iter.into_iter()
-                        .collect::<Box<[_]>>()
-                        .as_ref()
-                        .try_into()
-                        .unwrap()
-                })
-                .collect::<Box<[_]>>()
-                .as_ref()
-                .try_into()
-                .unwrap(),
-        )
+        Self(Matrix4x4Sisd::from_rows(rows))
     }
 
     pub fn rows(&self) -> &[[f32; 4]; 4] {
-        &self.0
+        &self.0.0
+    }
+
+    pub fn map(self, f: impl Fn(f32) -> f32) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// Returns the matrix's cells as a slice in row-major order.
+    pub fn flat_cells(&self) -> &[f32; 4 * 4] {
+        self.0.flat_cells()
     }
 
     /// Returns the rows of this matrix packed into two 256 bit vector registers, with the first
@@ -59,19 +90,12 @@ impl Matrix4x4Simd {
         (rows_0_1_m256, rows_2_3_m256)
     }
 
-    /// Returns the matrix's cells as a slice in row-major order.
-    pub fn flat_cells(&self) -> &[f32; 4 * 4] {
-        match bytemuck::try_cast_ref(&self.0) {
-            Ok(cells) => cells,
-            // `self.0` has the same size and alignment as `[f32; 4 * 4]`.
-            Err(_) => unreachable!(),
-        }
-    }
-
-    pub fn map(self, f: impl Fn(f32) -> f32) -> Self {
-        Self(self.0.map(|row| row.map(&f)))
-    }
-
+    /// Multiplies two matrices using AVX+FMA directly, without checking CPU support. Kept public
+    /// for callers who have already checked feature support themselves; most callers should use
+    /// [`Self::multiply_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` and `fma` target features.
     // Needs to be separate method since `target_feature` isn't supported in trait methods.
     #[target_feature(enable = "avx")]
     #[target_feature(enable = "fma")]
@@ -165,69 +189,580 @@ impl Matrix4x4Simd {
             result_rows_2_3
         };
 
-        match bytemuck::try_cast([result_rows_0_1, result_rows_2_3]) {
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => result,
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Multiplies two matrices, using the AVX+FMA [`Self::multiply`] when the running CPU
+    /// supports it, and otherwise falling back to [`Matrix4x4Sisd`]'s scalar multiplication.
+    /// Feature detection is performed once and cached.
+    pub fn multiply_dispatched(&self, rhs: &Self) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.multiply(rhs) }
+        } else {
+            Self::from(&Matrix4x4Sisd::from(*self) * &Matrix4x4Sisd::from(*rhs))
+        }
+    }
+
+    /// Multiplies this matrix by a column vector using AVX+FMA directly, without checking CPU
+    /// support. Most callers should use [`Self::multiply_vec4_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` and `fma` target features.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    #[target_feature(enable = "fma")]
+    pub fn multiply_vec4(&self, rhs: &Vec4) -> Vec4 {
+        let (self_rows_0_1, self_rows_2_3) = self.rows_m256();
+        Self::multiply_vec4_with_rows(self_rows_0_1, self_rows_2_3, rhs)
+    }
+
+    /// The core of [`Self::multiply_vec4`], taking the matrix's rows already packed into two
+    /// `__m256` registers. Factored out so [`Self::transform_many_avx`] can load the matrix's
+    /// rows once and reuse them across a whole slice of vectors.
+    #[target_feature(enable = "avx")]
+    #[target_feature(enable = "fma")]
+    fn multiply_vec4_with_rows(
+        self_rows_0_1: x86_64::__m256,
+        self_rows_2_3: x86_64::__m256,
+        rhs: &Vec4,
+    ) -> Vec4 {
+        // SAFETY: `rhs.flat_cells()` points to an array of length 4 with the same size and
+        // alignment as `__m128` (`Vec4` is `repr(align(16))`).
+        let rhs_m128 = unsafe { *(rhs.flat_cells().as_ptr() as *const x86_64::__m128) };
+        // Duplicate the vector into both 128-bit lanes, so it lines up against the two packed
+        // rows in `self_rows_0_1`/`self_rows_2_3`.
+        let rhs_rows = x86_64::_mm256_set_m128(rhs_m128, rhs_m128);
+
+        let zero = x86_64::_mm256_setzero_ps();
+        let products_0_1 = x86_64::_mm256_fmadd_ps(self_rows_0_1, rhs_rows, zero);
+        let products_2_3 = x86_64::_mm256_fmadd_ps(self_rows_2_3, rhs_rows, zero);
+
+        // Horizontally sum each row's four products twice, which leaves the row's dot product
+        // broadcast across its whole 128-bit lane.
+        let sums_0_1 = x86_64::_mm256_hadd_ps(products_0_1, products_0_1);
+        let sums_0_1 = x86_64::_mm256_hadd_ps(sums_0_1, sums_0_1);
+        let sums_2_3 = x86_64::_mm256_hadd_ps(products_2_3, products_2_3);
+        let sums_2_3 = x86_64::_mm256_hadd_ps(sums_2_3, sums_2_3);
+
+        let row_0 = x86_64::_mm256_cvtss_f32(sums_0_1);
+        let row_1 = x86_64::_mm_cvtss_f32(x86_64::_mm256_extractf128_ps::<1>(sums_0_1));
+        let row_2 = x86_64::_mm256_cvtss_f32(sums_2_3);
+        let row_3 = x86_64::_mm_cvtss_f32(x86_64::_mm256_extractf128_ps::<1>(sums_2_3));
+
+        Vec4::new(row_0, row_1, row_2, row_3)
+    }
+
+    /// Multiplies this matrix by a column vector, dispatching to [`Self::multiply_vec4`] when
+    /// the running CPU supports AVX+FMA, and otherwise falling back to scalar dot products.
+    pub fn multiply_vec4_dispatched(&self, rhs: &Vec4) -> Vec4 {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.multiply_vec4(rhs) }
+        } else {
+            let rows = self.rows();
+            Vec4::new(
+                rows[0][0] * rhs.x() + rows[0][1] * rhs.y() + rows[0][2] * rhs.z()
+                    + rows[0][3] * rhs.w(),
+                rows[1][0] * rhs.x() + rows[1][1] * rhs.y() + rows[1][2] * rhs.z()
+                    + rows[1][3] * rhs.w(),
+                rows[2][0] * rhs.x() + rows[2][1] * rhs.y() + rows[2][2] * rhs.z()
+                    + rows[2][3] * rhs.w(),
+                rows[3][0] * rhs.x() + rows[3][1] * rhs.y() + rows[3][2] * rhs.z()
+                    + rows[3][3] * rhs.w(),
+            )
+        }
+    }
+
+    /// Transforms a point `(x, y, z)`, treating it as the homogeneous vector `(x, y, z, 1)` and
+    /// dividing by the resulting `w` component.
+    pub fn transform_point(&self, point: (f32, f32, f32)) -> (f32, f32, f32) {
+        let result = self.multiply_vec4_dispatched(&Vec4::new(point.0, point.1, point.2, 1.));
+        (
+            result.x() / result.w(),
+            result.y() / result.w(),
+            result.z() / result.w(),
+        )
+    }
+
+    /// Transforms a direction vector `(x, y, z)`, treating it as the homogeneous vector
+    /// `(x, y, z, 0)`.
+    pub fn transform_vector(&self, vector: (f32, f32, f32)) -> (f32, f32, f32) {
+        let result = self.multiply_vec4_dispatched(&Vec4::new(vector.0, vector.1, vector.2, 0.));
+        (result.x(), result.y(), result.z())
+    }
+
+    /// Transposes the matrix using AVX directly, without checking CPU support. Most callers
+    /// should use [`Self::transpose_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    pub fn transpose(&self) -> Self {
+        let (rows_0_1, rows_2_3) = self.rows_m256();
+
+        let row_0 = x86_64::_mm256_castps256_ps128(rows_0_1);
+        let row_1 = x86_64::_mm256_extractf128_ps::<1>(rows_0_1);
+        let row_2 = x86_64::_mm256_castps256_ps128(rows_2_3);
+        let row_3 = x86_64::_mm256_extractf128_ps::<1>(rows_2_3);
+
+        // Classic SSE 4x4 transpose: unpack rows pairwise, then recombine the low/high halves
+        // of each pair into the transposed columns.
+        let tmp_0 = x86_64::_mm_unpacklo_ps(row_0, row_1);
+        let tmp_1 = x86_64::_mm_unpackhi_ps(row_0, row_1);
+        let tmp_2 = x86_64::_mm_unpacklo_ps(row_2, row_3);
+        let tmp_3 = x86_64::_mm_unpackhi_ps(row_2, row_3);
+
+        let column_0 = x86_64::_mm_movelh_ps(tmp_0, tmp_2);
+        let column_1 = x86_64::_mm_movehl_ps(tmp_2, tmp_0);
+        let column_2 = x86_64::_mm_movelh_ps(tmp_1, tmp_3);
+        let column_3 = x86_64::_mm_movehl_ps(tmp_3, tmp_1);
+
+        let result_rows_0_1 = x86_64::_mm256_set_m128(column_1, column_0);
+        let result_rows_2_3 = x86_64::_mm256_set_m128(column_3, column_2);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => result,
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Transposes the matrix, using the AVX [`Self::transpose`] when the running CPU supports
+    /// AVX+FMA, and otherwise falling back to [`Matrix4x4Sisd`]'s scalar transpose.
+    pub fn transpose_dispatched(&self) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.transpose() }
+        } else {
+            Self::from(Matrix4x4Sisd::from(*self).transpose())
+        }
+    }
+
+    /// Computes the matrix's determinant via the same cofactor expansion used by
+    /// [`Self::inverse`]; see [`Matrix4x4Sisd::determinant`] for the formula.
+    pub fn determinant(&self) -> f32 {
+        Matrix4x4Sisd::from(*self).determinant()
+    }
+
+    /// Inverts the matrix via the adjugate method, using AVX to divide every adjugate cell by
+    /// the determinant in one pass. The adjugate and determinant themselves are the same
+    /// irregular combination of 2x2 minors as [`Matrix4x4Sisd::inverse`], which doesn't lend
+    /// itself to further vectorization. Returns `None` if the matrix is singular. Most callers
+    /// should use [`Self::inverse_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    #[target_feature(enable = "avx")]
+    pub fn inverse(&self) -> Option<Self> {
+        let (adjugate, det) = Matrix4x4Sisd::from(*self).adjugate_and_determinant();
+        if det.abs() < crate::matrix_sisd::DETERMINANT_EPSILON {
+            return None;
+        }
+
+        let inv_det = x86_64::_mm256_set1_ps(1. / det);
+        let (adjugate_rows_0_1, adjugate_rows_2_3) = Self::from(adjugate).rows_m256();
+        let result_rows_0_1 = x86_64::_mm256_mul_ps(adjugate_rows_0_1, inv_det);
+        let result_rows_2_3 = x86_64::_mm256_mul_ps(adjugate_rows_2_3, inv_det);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => Some(result),
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Inverts the matrix, using AVX [`Self::inverse`] when the running CPU supports AVX+FMA, and
+    /// otherwise falling back to [`Matrix4x4Sisd`]'s scalar inverse.
+    pub fn inverse_dispatched(&self) -> Option<Self> {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.inverse() }
+        } else {
+            Matrix4x4Sisd::from(*self).inverse().map(Self::from)
+        }
+    }
+
+    /// Adds two matrices elementwise using AVX directly, without checking CPU support. Most
+    /// callers should use [`Self::add_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    pub fn add(&self, rhs: &Self) -> Self {
+        let (self_rows_0_1, self_rows_2_3) = self.rows_m256();
+        let (rhs_rows_0_1, rhs_rows_2_3) = rhs.rows_m256();
+
+        let result_rows_0_1 = x86_64::_mm256_add_ps(self_rows_0_1, rhs_rows_0_1);
+        let result_rows_2_3 = x86_64::_mm256_add_ps(self_rows_2_3, rhs_rows_2_3);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => result,
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Adds two matrices elementwise, using AVX [`Self::add`] when the running CPU supports AVX+FMA,
+    /// and otherwise falling back to [`Matrix4x4Sisd`]'s scalar addition.
+    pub fn add_dispatched(&self, rhs: &Self) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.add(rhs) }
+        } else {
+            Self::from(Matrix4x4Sisd::from(*self) + Matrix4x4Sisd::from(*rhs))
+        }
+    }
+
+    /// Subtracts two matrices elementwise using AVX directly, without checking CPU support. Most
+    /// callers should use [`Self::sub_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    pub fn sub(&self, rhs: &Self) -> Self {
+        let (self_rows_0_1, self_rows_2_3) = self.rows_m256();
+        let (rhs_rows_0_1, rhs_rows_2_3) = rhs.rows_m256();
+
+        let result_rows_0_1 = x86_64::_mm256_sub_ps(self_rows_0_1, rhs_rows_0_1);
+        let result_rows_2_3 = x86_64::_mm256_sub_ps(self_rows_2_3, rhs_rows_2_3);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => result,
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Subtracts two matrices elementwise, using AVX [`Self::sub`] when the running CPU supports
+    /// AVX+FMA, and otherwise falling back to [`Matrix4x4Sisd`]'s scalar subtraction.
+    pub fn sub_dispatched(&self, rhs: &Self) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.sub(rhs) }
+        } else {
+            Self::from(Matrix4x4Sisd::from(*self) - Matrix4x4Sisd::from(*rhs))
+        }
+    }
+
+    /// Multiplies every cell by `scalar` using AVX directly, without checking CPU support. Most
+    /// callers should use [`Self::scale_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    pub fn scale(&self, scalar: f32) -> Self {
+        let (rows_0_1, rows_2_3) = self.rows_m256();
+        let scalar = x86_64::_mm256_set1_ps(scalar);
+
+        let result_rows_0_1 = x86_64::_mm256_mul_ps(rows_0_1, scalar);
+        let result_rows_2_3 = x86_64::_mm256_mul_ps(rows_2_3, scalar);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
             Ok(result) => result,
             // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
             Err(_) => unreachable!(),
         }
     }
+
+    /// Multiplies every cell by `scalar`, using AVX [`Self::scale`] when the running CPU supports
+    /// AVX+FMA, and otherwise falling back to [`Matrix4x4Sisd`]'s scalar multiplication.
+    pub fn scale_dispatched(&self, scalar: f32) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.scale(scalar) }
+        } else {
+            Self::from(Matrix4x4Sisd::from(*self) * scalar)
+        }
+    }
+
+    /// Divides every cell by `scalar` using AVX directly, without checking CPU support. Most
+    /// callers should use [`Self::divide_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    pub fn divide(&self, scalar: f32) -> Self {
+        let (rows_0_1, rows_2_3) = self.rows_m256();
+        let scalar = x86_64::_mm256_set1_ps(scalar);
+
+        let result_rows_0_1 = x86_64::_mm256_div_ps(rows_0_1, scalar);
+        let result_rows_2_3 = x86_64::_mm256_div_ps(rows_2_3, scalar);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => result,
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Divides every cell by `scalar`, using AVX [`Self::divide`] when the running CPU supports
+    /// AVX+FMA, and otherwise falling back to [`Matrix4x4Sisd`]'s scalar division.
+    pub fn divide_dispatched(&self, scalar: f32) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.divide(scalar) }
+        } else {
+            Self::from(Matrix4x4Sisd::from(*self) / scalar)
+        }
+    }
+
+    /// Negates every cell using AVX directly, without checking CPU support. Most callers should
+    /// use [`Self::negate_dispatched`] instead.
+    ///
+    /// # Safety
+    /// The running CPU must support the `avx` target feature.
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    pub fn negate(&self) -> Self {
+        let (rows_0_1, rows_2_3) = self.rows_m256();
+        let zero = x86_64::_mm256_setzero_ps();
+
+        let result_rows_0_1 = x86_64::_mm256_sub_ps(zero, rows_0_1);
+        let result_rows_2_3 = x86_64::_mm256_sub_ps(zero, rows_2_3);
+
+        match bytemuck::try_cast::<_, Matrix4x4Simd>([result_rows_0_1, result_rows_2_3]) {
+            Ok(result) => result,
+            // `[__m256; 2]` has the same size and alignment as `Matrix4x4Simd`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Negates every cell, using AVX [`Self::negate`] when the running CPU supports AVX+FMA, and
+    /// otherwise falling back to [`Matrix4x4Sisd`]'s scalar negation.
+    pub fn negate_dispatched(&self) -> Self {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.negate() }
+        } else {
+            Self::from(-Matrix4x4Sisd::from(*self))
+        }
+    }
+
+    /// Multiplies each corresponding pair of matrices from `lhs` and `rhs`, writing the results
+    /// into `out`. Equivalent to calling [`Self::multiply_dispatched`] pairwise, but checks
+    /// AVX+FMA support once for the whole batch instead of once per pair.
+    ///
+    /// # Panics
+    /// Panics if `lhs`, `rhs`, and `out` don't all have the same length.
+    pub fn multiply_batch(lhs: &[Self], rhs: &[Self], out: &mut [Self]) {
+        assert_eq!(lhs.len(), rhs.len(), "lhs and rhs must have the same length");
+        assert_eq!(lhs.len(), out.len(), "lhs and out must have the same length");
+
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { Self::multiply_batch_avx(lhs, rhs, out) }
+        } else {
+            for ((l, r), o) in lhs.iter().zip(rhs).zip(out) {
+                let l = Matrix4x4Sisd::from(*l);
+                let r = Matrix4x4Sisd::from(*r);
+                *o = Self::from(&l * &r);
+            }
+        }
+    }
+
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    #[target_feature(enable = "fma")]
+    fn multiply_batch_avx(lhs: &[Self], rhs: &[Self], out: &mut [Self]) {
+        for ((l, r), o) in lhs.iter().zip(rhs).zip(out.iter_mut()) {
+            *o = l.multiply(r);
+        }
+    }
+
+    /// Transforms each vector in `vectors` in place by this matrix, the way repeated calls to
+    /// [`Self::multiply_vec4_dispatched`] would, but checking AVX+FMA support once for the whole
+    /// slice and keeping this matrix's rows packed in registers across every vector instead of
+    /// reloading them per call.
+    pub fn transform_many(&self, vectors: &mut [Vec4]) {
+        if avx_fma_supported() {
+            // SAFETY: we've just checked that all features are supported.
+            unsafe { self.transform_many_avx(vectors) }
+        } else {
+            for vector in vectors.iter_mut() {
+                *vector = self.multiply_vec4_dispatched(vector);
+            }
+        }
+    }
+
+    // Needs to be separate method since `target_feature` isn't supported in trait methods.
+    #[target_feature(enable = "avx")]
+    #[target_feature(enable = "fma")]
+    fn transform_many_avx(&self, vectors: &mut [Vec4]) {
+        let (self_rows_0_1, self_rows_2_3) = self.rows_m256();
+        for vector in vectors.iter_mut() {
+            *vector = Self::multiply_vec4_with_rows(self_rows_0_1, self_rows_2_3, vector);
+        }
+    }
+}
+
+impl ops::Mul<&Vec4> for &Matrix4x4Simd {
+    type Output = Vec4;
+
+    fn mul(self, rhs: &Vec4) -> Self::Output {
+        self.multiply_vec4_dispatched(rhs)
+    }
 }
 
 impl From<Matrix4x4Sisd> for Matrix4x4Simd {
     fn from(value: Matrix4x4Sisd) -> Self {
-        Self(value.0)
+        Self(value)
     }
 }
 
 impl From<Matrix4x4Simd> for Matrix4x4Sisd {
     fn from(value: Matrix4x4Simd) -> Self {
-        Self(value.0)
+        value.0
     }
 }
 
 impl ops::Index<(usize, usize)> for Matrix4x4Simd {
     type Output = f32;
     fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.0[index.1][index.0]
+        &self.0[index]
     }
 }
 
 impl ops::IndexMut<(usize, usize)> for Matrix4x4Simd {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.0[index.1][index.0]
+        &mut self.0[index]
     }
 }
 
-impl ops::Mul<&Matrix4x4Simd> for &Matrix4x4Simd {
+/// Implements `$trait` for every owned/reference permutation of a binary `Matrix4x4Simd`
+/// operator, delegating to the dispatched method named `$dispatched`.
+macro_rules! impl_simd_matrix_binop {
+    ($trait:ident, $method:ident, $dispatched:ident) => {
+        impl ops::$trait<Matrix4x4Simd> for Matrix4x4Simd {
+            type Output = Matrix4x4Simd;
+            fn $method(self, rhs: Matrix4x4Simd) -> Self::Output {
+                self.$dispatched(&rhs)
+            }
+        }
+
+        impl ops::$trait<&Matrix4x4Simd> for Matrix4x4Simd {
+            type Output = Matrix4x4Simd;
+            fn $method(self, rhs: &Matrix4x4Simd) -> Self::Output {
+                self.$dispatched(rhs)
+            }
+        }
+
+        impl ops::$trait<Matrix4x4Simd> for &Matrix4x4Simd {
+            type Output = Matrix4x4Simd;
+            fn $method(self, rhs: Matrix4x4Simd) -> Self::Output {
+                self.$dispatched(&rhs)
+            }
+        }
+
+        impl ops::$trait<&Matrix4x4Simd> for &Matrix4x4Simd {
+            type Output = Matrix4x4Simd;
+            fn $method(self, rhs: &Matrix4x4Simd) -> Self::Output {
+                self.$dispatched(rhs)
+            }
+        }
+    };
+}
+
+impl_simd_matrix_binop!(Mul, mul, multiply_dispatched);
+impl_simd_matrix_binop!(Add, add, add_dispatched);
+impl_simd_matrix_binop!(Sub, sub, sub_dispatched);
+
+/// Implements `$trait` (`AddAssign`/`SubAssign`) for both the owned and by-reference right-hand
+/// side, delegating to the dispatched method named `$dispatched`.
+macro_rules! impl_simd_matrix_binop_assign {
+    ($trait:ident, $method:ident, $dispatched:ident) => {
+        impl ops::$trait<Matrix4x4Simd> for Matrix4x4Simd {
+            fn $method(&mut self, rhs: Matrix4x4Simd) {
+                *self = self.$dispatched(&rhs);
+            }
+        }
+
+        impl ops::$trait<&Matrix4x4Simd> for Matrix4x4Simd {
+            fn $method(&mut self, rhs: &Matrix4x4Simd) {
+                *self = self.$dispatched(rhs);
+            }
+        }
+    };
+}
+
+impl_simd_matrix_binop_assign!(AddAssign, add_assign, add_dispatched);
+impl_simd_matrix_binop_assign!(SubAssign, sub_assign, sub_dispatched);
+
+/// Implements scalar `$trait` (`Mul<f32>`/`Div<f32>`) for both owned and by-reference
+/// `Matrix4x4Simd`, delegating to the dispatched method named `$dispatched`.
+macro_rules! impl_simd_scalar_binop {
+    ($trait:ident, $method:ident, $dispatched:ident) => {
+        impl ops::$trait<f32> for Matrix4x4Simd {
+            type Output = Matrix4x4Simd;
+            fn $method(self, rhs: f32) -> Self::Output {
+                self.$dispatched(rhs)
+            }
+        }
+
+        impl ops::$trait<f32> for &Matrix4x4Simd {
+            type Output = Matrix4x4Simd;
+            fn $method(self, rhs: f32) -> Self::Output {
+                self.$dispatched(rhs)
+            }
+        }
+    };
+}
+
+impl_simd_scalar_binop!(Mul, mul, scale_dispatched);
+impl_simd_scalar_binop!(Div, div, divide_dispatched);
+
+/// Implements scalar `$trait` (`MulAssign<f32>`/`DivAssign<f32>`) in place, delegating to the
+/// dispatched method named `$dispatched`.
+macro_rules! impl_simd_scalar_binop_assign {
+    ($trait:ident, $method:ident, $dispatched:ident) => {
+        impl ops::$trait<f32> for Matrix4x4Simd {
+            fn $method(&mut self, rhs: f32) {
+                *self = self.$dispatched(rhs);
+            }
+        }
+    };
+}
+
+impl_simd_scalar_binop_assign!(MulAssign, mul_assign, scale_dispatched);
+impl_simd_scalar_binop_assign!(DivAssign, div_assign, divide_dispatched);
+
+impl ops::Neg for Matrix4x4Simd {
     type Output = Matrix4x4Simd;
+    fn neg(self) -> Self::Output {
+        self.negate_dispatched()
+    }
+}
 
-    fn mul(self, rhs: &Matrix4x4Simd) -> Self::Output {
-        assert!(
-            std::arch::is_x86_feature_detected!("avx")
-                && std::arch::is_x86_feature_detected!("fma")
-        );
-        // SAFETY: we've checked that all features are supported
-        unsafe { self.multiply(rhs) }
+impl ops::Neg for &Matrix4x4Simd {
+    type Output = Matrix4x4Simd;
+    fn neg(self) -> Self::Output {
+        self.negate_dispatched()
     }
 }
 
 impl approx::AbsDiffEq for Matrix4x4Simd {
-    type Epsilon = <f32 as AbsDiffEq>::Epsilon;
+    type Epsilon = <Matrix4x4Sisd as approx::AbsDiffEq>::Epsilon;
     fn default_epsilon() -> Self::Epsilon {
-        f32::default_epsilon()
+        Matrix4x4Sisd::default_epsilon()
     }
 
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        self.flat_cells()
-            .iter()
-            .zip(other.flat_cells().iter())
-            .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon))
+        self.0.abs_diff_eq(&other.0, epsilon)
     }
 }
 
 impl approx::RelativeEq for Matrix4x4Simd {
     fn default_max_relative() -> Self::Epsilon {
-        f32::default_max_relative()
+        Matrix4x4Sisd::default_max_relative()
     }
 
     fn relative_eq(
@@ -236,23 +771,17 @@ impl approx::RelativeEq for Matrix4x4Simd {
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
     ) -> bool {
-        self.flat_cells()
-            .iter()
-            .zip(other.flat_cells().iter())
-            .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative))
+        self.0.relative_eq(&other.0, epsilon, max_relative)
     }
 }
 
 impl approx::UlpsEq for Matrix4x4Simd {
     fn default_max_ulps() -> u32 {
-        f32::default_max_ulps()
+        Matrix4x4Sisd::default_max_ulps()
     }
 
     fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-        self.flat_cells()
-            .iter()
-            .zip(other.flat_cells().iter())
-            .all(|(lhs, rhs)| lhs.ulps_eq(rhs, epsilon, max_ulps))
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
     }
 }
 
@@ -260,7 +789,7 @@ impl Debug for Matrix4x4Simd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("Matrix4x4Simd([")?;
         let mut first_row_written = false;
-        for row in self.0.iter() {
+        for row in self.rows().iter() {
             if f.alternate() {
                 if !first_row_written {
                     f.write_str("\n")?
@@ -304,9 +833,7 @@ mod test {
         ]);
 
         // Introduce floating point error.
-        let modified = matrix
-            .clone()
-            .map(|cell| (cell * 10000. + 3.) / 10000. - 3. / 10000.);
+        let modified = matrix.map(|cell| (cell * 10000. + 3.) / 10000. - 3. / 10000.);
 
         assert_ne!(matrix, modified);
         assert_abs_diff_eq!(matrix, modified);
@@ -323,7 +850,7 @@ mod test {
             [13., 14., 15., 16.],
         ]);
 
-        assert_abs_diff_eq!(matrix, &matrix * &Matrix4x4Simd::IDENTITY);
+        assert_abs_diff_eq!(matrix, matrix * Matrix4x4Simd::IDENTITY);
     }
 
     #[test]
@@ -347,6 +874,229 @@ mod test {
             [8., 2., 8., 2.],
         ]);
 
-        assert_abs_diff_eq!(expected, &a * &b);
+        assert_abs_diff_eq!(expected, a * b);
+    }
+
+    #[test]
+    fn test_multiply_dispatched_matches_scalar_fallback() {
+        let a = Matrix4x4Simd::from_rows([
+            [1., 2., 0., 1.],
+            [0., 1., 3., 2.],
+            [4., 0., 1., 0.],
+            [2., 1., 0., 1.],
+        ]);
+        let b = Matrix4x4Simd::from_rows([
+            [2., 1., 3., 0.],
+            [1., 0., 2., 1.],
+            [0., 1., 1., 2.],
+            [3., 0., 0., 1.],
+        ]);
+
+        let scalar_result = Matrix4x4Simd::from(
+            &Matrix4x4Sisd::from(a) * &Matrix4x4Sisd::from(b),
+        );
+
+        assert_abs_diff_eq!(scalar_result, a.multiply_dispatched(&b));
+    }
+
+    #[test]
+    fn test_multiply_vec4() {
+        let matrix = Matrix4x4Simd::from_rows([
+            [1., 2., 0., 1.],
+            [0., 1., 3., 2.],
+            [4., 0., 1., 0.],
+            [2., 1., 0., 1.],
+        ]);
+        let vector = Vec4::new(1., 2., 3., 4.);
+
+        // row 0: 1*1 + 2*2 + 0*3 + 1*4 = 9
+        // row 1: 0*1 + 1*2 + 3*3 + 2*4 = 19
+        // row 2: 4*1 + 0*2 + 1*3 + 0*4 = 7
+        // row 3: 2*1 + 1*2 + 0*3 + 1*4 = 8
+        let expected = Vec4::new(9., 19., 7., 8.);
+
+        assert_eq!(expected, &matrix * &vector);
+        assert_eq!(expected, matrix.multiply_vec4_dispatched(&vector));
+    }
+
+    #[test]
+    fn test_transform_point_and_vector() {
+        let translation = Matrix4x4Simd::from_rows([
+            [1., 0., 0., 10.],
+            [0., 1., 0., 20.],
+            [0., 0., 1., 30.],
+            [0., 0., 0., 1.],
+        ]);
+
+        assert_eq!(translation.transform_point((1., 2., 3.)), (11., 22., 33.));
+        // Direction vectors aren't affected by translation.
+        assert_eq!(translation.transform_vector((1., 2., 3.)), (1., 2., 3.));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let matrix = Matrix4x4Simd::from_rows([
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+        let expected = Matrix4x4Simd::from_rows([
+            [1., 5., 9., 13.],
+            [2., 6., 10., 14.],
+            [3., 7., 11., 15.],
+            [4., 8., 12., 16.],
+        ]);
+
+        assert_abs_diff_eq!(expected, matrix.transpose_dispatched());
+        assert_abs_diff_eq!(
+            Matrix4x4Sisd::from(matrix).transpose(),
+            Matrix4x4Sisd::from(matrix.transpose_dispatched())
+        );
+    }
+
+    #[test]
+    fn test_determinant_singular() {
+        let matrix = Matrix4x4Simd::from_rows([
+            [1., 2., 3., 4.],
+            [1., 2., 3., 4.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+
+        assert_abs_diff_eq!(0., matrix.determinant());
+        assert!(matrix.inverse_dispatched().is_none());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix = Matrix4x4Simd::from_rows([
+            [1., 2., 0., 1.],
+            [0., 1., 3., 2.],
+            [4., 0., 1., 0.],
+            [2., 1., 0., 1.],
+        ]);
+
+        let inverse = matrix.inverse_dispatched().expect("matrix should be invertible");
+
+        assert_abs_diff_eq!(Matrix4x4Simd::IDENTITY, matrix * inverse, epsilon = 1e-4);
+        assert_abs_diff_eq!(Matrix4x4Simd::IDENTITY, inverse * matrix, epsilon = 1e-4);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)] // Deliberately exercises every ref/value permutation.
+    fn test_add_and_sub_permutations() {
+        let a = Matrix4x4Simd::from_rows([
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+        let b = Matrix4x4Simd::from_rows([
+            [16., 15., 14., 13.],
+            [12., 11., 10., 9.],
+            [8., 7., 6., 5.],
+            [4., 3., 2., 1.],
+        ]);
+        let expected_sum = Matrix4x4Simd::from_rows([[17.; 4]; 4]);
+
+        assert_abs_diff_eq!(expected_sum, a + b);
+        assert_abs_diff_eq!(expected_sum, a + &b);
+        assert_abs_diff_eq!(expected_sum, &a + b);
+        assert_abs_diff_eq!(expected_sum, &a + &b);
+        assert_abs_diff_eq!(expected_sum, a.add_dispatched(&b));
+        assert_abs_diff_eq!(a, (a + b) - b);
+
+        let mut sum = a;
+        sum += b;
+        assert_abs_diff_eq!(expected_sum, sum);
+        let mut sum = a;
+        sum += &b;
+        assert_abs_diff_eq!(expected_sum, sum);
+    }
+
+    #[test]
+    fn test_scalar_mul_div_and_neg() {
+        let a = Matrix4x4Simd::from_rows([
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+        let expected_scaled = a.map(|cell| cell * 2.);
+
+        assert_abs_diff_eq!(expected_scaled, a * 2.);
+        assert_abs_diff_eq!(expected_scaled, &a * 2.);
+        assert_abs_diff_eq!(expected_scaled, a.scale_dispatched(2.));
+        assert_abs_diff_eq!(a, expected_scaled / 2.);
+        assert_abs_diff_eq!(a, expected_scaled.divide_dispatched(2.));
+        assert_abs_diff_eq!(-a, a.map(|cell| -cell));
+        assert_abs_diff_eq!(-a, -&a);
+
+        let mut scaled = a;
+        scaled *= 2.;
+        assert_abs_diff_eq!(expected_scaled, scaled);
+        scaled /= 2.;
+        assert_abs_diff_eq!(a, scaled);
+    }
+
+    #[test]
+    fn test_multiply_batch() {
+        let a = Matrix4x4Simd::from_rows([
+            [1., 2., 0., 1.],
+            [0., 1., 3., 2.],
+            [4., 0., 1., 0.],
+            [2., 1., 0., 1.],
+        ]);
+        let b = Matrix4x4Simd::from_rows([
+            [2., 1., 3., 0.],
+            [1., 0., 2., 1.],
+            [0., 1., 1., 2.],
+            [3., 0., 0., 1.],
+        ]);
+
+        let lhs = [a, b, a];
+        let rhs = [b, a, b];
+        let mut out = [Matrix4x4Simd::ZERO; 3];
+
+        Matrix4x4Simd::multiply_batch(&lhs, &rhs, &mut out);
+
+        for (l, r, o) in [(a, b, out[0]), (b, a, out[1]), (a, b, out[2])] {
+            assert_abs_diff_eq!(l * r, o);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multiply_batch_mismatched_lengths_panics() {
+        let lhs = [Matrix4x4Simd::IDENTITY];
+        let rhs = [Matrix4x4Simd::IDENTITY, Matrix4x4Simd::IDENTITY];
+        let mut out = [Matrix4x4Simd::ZERO];
+
+        Matrix4x4Simd::multiply_batch(&lhs, &rhs, &mut out);
+    }
+
+    #[test]
+    fn test_transform_many() {
+        let matrix = Matrix4x4Simd::from_rows([
+            [1., 0., 0., 10.],
+            [0., 1., 0., 20.],
+            [0., 0., 1., 30.],
+            [0., 0., 0., 1.],
+        ]);
+        let mut vectors = [
+            Vec4::new(1., 2., 3., 1.),
+            Vec4::new(0., 0., 0., 1.),
+            Vec4::new(-1., -2., -3., 0.),
+        ];
+        let expected = [
+            &matrix * &Vec4::new(1., 2., 3., 1.),
+            &matrix * &Vec4::new(0., 0., 0., 1.),
+            &matrix * &Vec4::new(-1., -2., -3., 0.),
+        ];
+
+        matrix.transform_many(&mut vectors);
+
+        assert_eq!(expected, vectors);
     }
 }
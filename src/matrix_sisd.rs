@@ -1,9 +1,6 @@
-use std::ops;
+use crate::matrix::Matrix;
 
-use approx::AbsDiffEq;
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct Matrix4x4Sisd(pub [[f32; 4]; 4]);
+pub type Matrix4x4Sisd = Matrix<f32, 4, 4>;
 
 impl Matrix4x4Sisd {
     pub const ZERO: Self = Self([[0.; 4]; 4]);
@@ -15,107 +12,88 @@ impl Matrix4x4Sisd {
         [0., 0., 0., 1.],
     ]);
 
-    pub fn from_rows(rows: impl IntoIterator<Item = impl IntoIterator<Item = f32>>) -> Self {
-        Self(
-            rows.into_iter()
-                .map(|iter| {
-                    iter.into_iter()
-                        .collect::<Box<[_]>>()
-                        .as_ref()
-                        .try_into()
-                        .unwrap()
-                })
-                .collect::<Box<[_]>>()
-                .as_ref()
-                .try_into()
-                .unwrap(),
-        )
-    }
-
+    /// Returns the matrix's cells as a slice in row-major order.
     pub fn flat_cells(&self) -> &[f32; 4 * 4] {
         bytemuck::cast_ref(&self.0)
     }
 
-    pub fn map(self, f: impl Fn(f32) -> f32) -> Self {
-        Self(self.0.map(|row| row.map(&f)))
+    pub fn transpose(&self) -> Self {
+        Self(std::array::from_fn(|row| std::array::from_fn(|col| self.0[col][row])))
     }
-}
-
-impl ops::Index<(usize, usize)> for Matrix4x4Sisd {
-    type Output = f32;
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.0[index.1][index.0]
-    }
-}
 
-impl ops::IndexMut<(usize, usize)> for Matrix4x4Sisd {
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.0[index.1][index.0]
+    pub fn determinant(&self) -> f32 {
+        self.adjugate_and_determinant().1
     }
-}
 
-impl ops::Mul<&Matrix4x4Sisd> for &Matrix4x4Sisd {
-    type Output = Matrix4x4Sisd;
-    fn mul(self, rhs: &Matrix4x4Sisd) -> Self::Output {
-        let mut result = Matrix4x4Sisd::ZERO;
-        for (row, row_cells) in result.0.iter_mut().enumerate() {
-            for (column, cell) in row_cells.iter_mut().enumerate() {
-                *cell = (0..4)
-                    .map(|column| self[(column, row)])
-                    .zip((0..4).map(|row| rhs[(column, row)]))
-                    .map(|(a, b)| a * b)
-                    .sum();
-            }
+    /// Inverts the matrix via the adjugate method: `inverse = adjugate(self) / det(self)`,
+    /// where the adjugate is the transpose of the cofactor matrix. Returns `None` if the matrix
+    /// is singular (its determinant is within [`DETERMINANT_EPSILON`] of zero).
+    pub fn inverse(&self) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        if det.abs() < DETERMINANT_EPSILON {
+            return None;
         }
 
-        result
-    }
-}
-
-impl approx::AbsDiffEq for Matrix4x4Sisd {
-    type Epsilon = <f32 as AbsDiffEq>::Epsilon;
-    fn default_epsilon() -> Self::Epsilon {
-        f32::default_epsilon()
+        Some(adjugate.map(|cell| cell / det))
     }
 
-    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        self.flat_cells()
-            .iter()
-            .zip(other.flat_cells().iter())
-            .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon))
-    }
-}
-
-impl approx::RelativeEq for Matrix4x4Sisd {
-    fn default_max_relative() -> Self::Epsilon {
-        f32::default_max_relative()
-    }
+    /// Computes the unscaled adjugate matrix together with the determinant, using the twelve
+    /// 2x2 minors of the classic 4x4 cofactor expansion. `self.inverse()` is
+    /// `adjugate / determinant`.
+    pub(crate) fn adjugate_and_determinant(&self) -> (Self, f32) {
+        let rows = &self.0;
+
+        // The six 2x2 minors from the top two rows, paired by column.
+        let s0 = rows[0][0] * rows[1][1] - rows[0][1] * rows[1][0];
+        let s1 = rows[0][0] * rows[1][2] - rows[0][2] * rows[1][0];
+        let s2 = rows[0][0] * rows[1][3] - rows[0][3] * rows[1][0];
+        let s3 = rows[0][1] * rows[1][2] - rows[0][2] * rows[1][1];
+        let s4 = rows[0][1] * rows[1][3] - rows[0][3] * rows[1][1];
+        let s5 = rows[0][2] * rows[1][3] - rows[0][3] * rows[1][2];
+
+        // The complementary six 2x2 minors from the bottom two rows.
+        let c5 = rows[2][2] * rows[3][3] - rows[2][3] * rows[3][2];
+        let c4 = rows[2][1] * rows[3][3] - rows[2][3] * rows[3][1];
+        let c3 = rows[2][1] * rows[3][2] - rows[2][2] * rows[3][1];
+        let c2 = rows[2][0] * rows[3][3] - rows[2][3] * rows[3][0];
+        let c1 = rows[2][0] * rows[3][2] - rows[2][2] * rows[3][0];
+        let c0 = rows[2][0] * rows[3][1] - rows[2][1] * rows[3][0];
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+        let adjugate = Self([
+            [
+                rows[1][1] * c5 - rows[1][2] * c4 + rows[1][3] * c3,
+                -rows[0][1] * c5 + rows[0][2] * c4 - rows[0][3] * c3,
+                rows[3][1] * s5 - rows[3][2] * s4 + rows[3][3] * s3,
+                -rows[2][1] * s5 + rows[2][2] * s4 - rows[2][3] * s3,
+            ],
+            [
+                -rows[1][0] * c5 + rows[1][2] * c2 - rows[1][3] * c1,
+                rows[0][0] * c5 - rows[0][2] * c2 + rows[0][3] * c1,
+                -rows[3][0] * s5 + rows[3][2] * s2 - rows[3][3] * s1,
+                rows[2][0] * s5 - rows[2][2] * s2 + rows[2][3] * s1,
+            ],
+            [
+                rows[1][0] * c4 - rows[1][1] * c2 + rows[1][3] * c0,
+                -rows[0][0] * c4 + rows[0][1] * c2 - rows[0][3] * c0,
+                rows[3][0] * s4 - rows[3][1] * s2 + rows[3][3] * s0,
+                -rows[2][0] * s4 + rows[2][1] * s2 - rows[2][3] * s0,
+            ],
+            [
+                -rows[1][0] * c3 + rows[1][1] * c1 - rows[1][2] * c0,
+                rows[0][0] * c3 - rows[0][1] * c1 + rows[0][2] * c0,
+                -rows[3][0] * s3 + rows[3][1] * s1 - rows[3][2] * s0,
+                rows[2][0] * s3 - rows[2][1] * s1 + rows[2][2] * s0,
+            ],
+        ]);
 
-    fn relative_eq(
-        &self,
-        other: &Self,
-        epsilon: Self::Epsilon,
-        max_relative: Self::Epsilon,
-    ) -> bool {
-        self.flat_cells()
-            .iter()
-            .zip(other.flat_cells().iter())
-            .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative))
+        (adjugate, det)
     }
 }
 
-impl approx::UlpsEq for Matrix4x4Sisd {
-    fn default_max_ulps() -> u32 {
-        f32::default_max_ulps()
-    }
-
-    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-        self.flat_cells()
-            .iter()
-            .zip(other.flat_cells().iter())
-            .all(|(lhs, rhs)| lhs.ulps_eq(rhs, epsilon, max_ulps))
-    }
-}
+/// Determinants with an absolute value below this are treated as singular.
+pub(crate) const DETERMINANT_EPSILON: f32 = 1e-6;
 
 #[cfg(test)]
 mod test {
@@ -133,9 +111,7 @@ mod test {
         ]);
 
         // Introduce floating point error.
-        let modified = matrix
-            .clone()
-            .map(|cell| (cell * 10000. + 3.) / 10000. - 3. / 10000.);
+        let modified = matrix.map(|cell| (cell * 10000. + 3.) / 10000. - 3. / 10000.);
 
         assert_ne!(matrix, modified);
         assert_abs_diff_eq!(matrix, modified);
@@ -178,4 +154,52 @@ mod test {
 
         assert_abs_diff_eq!(expected, &a * &b);
     }
+
+    #[test]
+    fn test_transpose() {
+        let matrix = Matrix4x4Sisd::from_rows([
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+        let expected = Matrix4x4Sisd::from_rows([
+            [1., 5., 9., 13.],
+            [2., 6., 10., 14.],
+            [3., 7., 11., 15.],
+            [4., 8., 12., 16.],
+        ]);
+
+        assert_abs_diff_eq!(expected, matrix.transpose());
+        assert_abs_diff_eq!(matrix, matrix.transpose().transpose());
+    }
+
+    #[test]
+    fn test_determinant_singular() {
+        // A matrix with two identical rows is singular.
+        let matrix = Matrix4x4Sisd::from_rows([
+            [1., 2., 3., 4.],
+            [1., 2., 3., 4.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+
+        assert_abs_diff_eq!(0., matrix.determinant());
+        assert!(matrix.inverse().is_none());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix = Matrix4x4Sisd::from_rows([
+            [1., 2., 0., 1.],
+            [0., 1., 3., 2.],
+            [4., 0., 1., 0.],
+            [2., 1., 0., 1.],
+        ]);
+
+        let inverse = matrix.inverse().expect("matrix should be invertible");
+
+        assert_abs_diff_eq!(Matrix4x4Sisd::IDENTITY, &matrix * &inverse, epsilon = 1e-4);
+        assert_abs_diff_eq!(Matrix4x4Sisd::IDENTITY, &inverse * &matrix, epsilon = 1e-4);
+    }
 }
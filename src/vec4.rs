@@ -0,0 +1,45 @@
+/// A 4-component vector, used as the right-hand side of matrix-vector products with
+/// [`Matrix4x4Simd`](crate::matrix_simd::Matrix4x4Simd).
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::AnyBitPattern)]
+#[repr(C, align(16))]
+pub struct Vec4([f32; 4]);
+
+impl Vec4 {
+    pub const ZERO: Self = Self([0.; 4]);
+
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self([x, y, z, w])
+    }
+
+    pub fn x(&self) -> f32 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> f32 {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> f32 {
+        self.0[2]
+    }
+
+    pub fn w(&self) -> f32 {
+        self.0[3]
+    }
+
+    /// Returns the vector's cells in `[x, y, z, w]` order.
+    pub fn flat_cells(&self) -> &[f32; 4] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_accessors() {
+        let v = Vec4::new(1., 2., 3., 4.);
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (1., 2., 3., 4.));
+    }
+}